@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::env;
+use std::future::{ready, Ready};
+
+use actix_web::body::EitherBody;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{Error, HttpResponse};
+use futures_util::future::LocalBoxFuture;
+use serde::Serialize;
+
+/// What a bearer token is allowed to do. `ReadOnly` tokens may hit
+/// read-only routes but are rejected by write endpoints such as
+/// `create_reservation`. `CatalogAdmin` is kept distinct from
+/// `ReservationCreate` because importing a catalog file overwrites shared
+/// data for every user, a much more destructive capability than booking a
+/// single reservation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenScope {
+    ReadOnly,
+    ReservationCreate,
+    CatalogAdmin,
+}
+
+/// Bearer tokens configured for this server, loaded once at startup from
+/// the `API_TOKENS` environment variable.
+///
+/// The expected format is a comma-separated list of `token:scope` pairs,
+/// e.g. `API_TOKENS=readtoken123:read,writetoken456:reserve,admintoken789:admin`.
+/// Tokens without a recognized scope suffix default to read-only.
+#[derive(Debug, Clone, Default)]
+pub struct TokenStore {
+    tokens: HashMap<String, TokenScope>,
+}
+
+impl TokenStore {
+    pub fn from_env() -> Self {
+        let mut tokens = HashMap::new();
+
+        if let Ok(raw) = env::var("API_TOKENS") {
+            for entry in raw.split(',') {
+                let entry = entry.trim();
+                if entry.is_empty() {
+                    continue;
+                }
+
+                let (token, scope) = match entry.split_once(':') {
+                    Some((token, "reserve")) => (token, TokenScope::ReservationCreate),
+                    Some((token, "admin")) => (token, TokenScope::CatalogAdmin),
+                    Some((token, _)) => (token, TokenScope::ReadOnly),
+                    None => (entry, TokenScope::ReadOnly),
+                };
+
+                tokens.insert(token.to_string(), scope);
+            }
+        }
+
+        Self { tokens }
+    }
+
+    fn scope_for(&self, token: &str) -> Option<TokenScope> {
+        self.tokens.get(token).copied()
+    }
+}
+
+#[derive(Serialize)]
+struct AuthErrorBody {
+    error: &'static str,
+}
+
+fn unauthorized(message: &'static str) -> HttpResponse {
+    HttpResponse::Unauthorized().json(AuthErrorBody { error: message })
+}
+
+/// Actix middleware requiring `Authorization: Bearer <token>` and, when
+/// `required_scope` is set, that the token carries at least that scope.
+/// `TokenScope::ReservationCreate` and `TokenScope::CatalogAdmin` are each
+/// treated as a superset of `ReadOnly` only, not of each other.
+pub struct BearerAuth {
+    required_scope: TokenScope,
+}
+
+impl BearerAuth {
+    pub fn read_only() -> Self {
+        Self {
+            required_scope: TokenScope::ReadOnly,
+        }
+    }
+
+    pub fn reservation_create() -> Self {
+        Self {
+            required_scope: TokenScope::ReservationCreate,
+        }
+    }
+
+    pub fn catalog_admin() -> Self {
+        Self {
+            required_scope: TokenScope::CatalogAdmin,
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for BearerAuth
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = BearerAuthMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(BearerAuthMiddleware {
+            service,
+            required_scope: self.required_scope,
+        }))
+    }
+}
+
+pub struct BearerAuthMiddleware<S> {
+    service: S,
+    required_scope: TokenScope,
+}
+
+impl<S, B> Service<ServiceRequest> for BearerAuthMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let required_scope = self.required_scope;
+
+        let token_store = req.app_data::<actix_web::web::Data<TokenStore>>().cloned();
+        let header_token = req
+            .headers()
+            .get("Authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(|token| token.to_string());
+
+        let rejection = match (&token_store, &header_token) {
+            (None, _) => Some("server has no configured bearer tokens"),
+            (_, None) => Some("missing bearer token"),
+            (Some(store), Some(token)) => match store.scope_for(token) {
+                None => Some("invalid bearer token"),
+                Some(scope) if !scope_satisfies(required_scope, scope) => {
+                    Some("token does not have the required scope")
+                }
+                Some(_) => None,
+            },
+        };
+
+        if let Some(message) = rejection {
+            let response = req.into_response(unauthorized(message)).map_into_right_body();
+            return Box::pin(async move { Ok(response) });
+        }
+
+        let fut = self.service.call(req);
+        Box::pin(async move { fut.await.map(|res| res.map_into_left_body()) })
+    }
+}
+
+fn scope_satisfies(required: TokenScope, actual: TokenScope) -> bool {
+    match required {
+        TokenScope::ReadOnly => true,
+        TokenScope::ReservationCreate => actual == TokenScope::ReservationCreate,
+        TokenScope::CatalogAdmin => actual == TokenScope::CatalogAdmin,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `TokenStore::from_env` reads a process-wide environment variable, and
+    // cargo runs tests in parallel by default; guard each from_env test so
+    // they don't stomp on each other's `API_TOKENS` value.
+    static ENV_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    fn scope_satisfies_read_only_route_accepts_any_token() {
+        assert!(scope_satisfies(TokenScope::ReadOnly, TokenScope::ReadOnly));
+        assert!(scope_satisfies(TokenScope::ReadOnly, TokenScope::ReservationCreate));
+    }
+
+    #[test]
+    fn scope_satisfies_reservation_create_route_rejects_read_only_token() {
+        assert!(!scope_satisfies(TokenScope::ReservationCreate, TokenScope::ReadOnly));
+    }
+
+    #[test]
+    fn scope_satisfies_reservation_create_route_accepts_reservation_create_token() {
+        assert!(scope_satisfies(TokenScope::ReservationCreate, TokenScope::ReservationCreate));
+    }
+
+    #[test]
+    fn scope_satisfies_catalog_admin_route_rejects_reservation_create_token() {
+        assert!(!scope_satisfies(TokenScope::CatalogAdmin, TokenScope::ReservationCreate));
+    }
+
+    #[test]
+    fn scope_satisfies_catalog_admin_route_accepts_catalog_admin_token() {
+        assert!(scope_satisfies(TokenScope::CatalogAdmin, TokenScope::CatalogAdmin));
+    }
+
+    #[test]
+    fn from_env_parses_admin_suffix_as_catalog_admin() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::set_var("API_TOKENS", "admintoken789:admin");
+        let store = TokenStore::from_env();
+        env::remove_var("API_TOKENS");
+        assert_eq!(store.scope_for("admintoken789"), Some(TokenScope::CatalogAdmin));
+    }
+
+    #[test]
+    fn from_env_parses_reserve_suffix_as_reservation_create() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::set_var("API_TOKENS", "writetoken456:reserve");
+        let store = TokenStore::from_env();
+        env::remove_var("API_TOKENS");
+        assert_eq!(store.scope_for("writetoken456"), Some(TokenScope::ReservationCreate));
+    }
+
+    #[test]
+    fn from_env_defaults_unrecognized_or_missing_suffix_to_read_only() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::set_var("API_TOKENS", "readtoken123:read,bareToken");
+        let store = TokenStore::from_env();
+        env::remove_var("API_TOKENS");
+        assert_eq!(store.scope_for("readtoken123"), Some(TokenScope::ReadOnly));
+        assert_eq!(store.scope_for("bareToken"), Some(TokenScope::ReadOnly));
+    }
+
+    #[test]
+    fn from_env_ignores_blank_entries_and_unknown_tokens() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::set_var("API_TOKENS", "readtoken123:read,,  ");
+        let store = TokenStore::from_env();
+        env::remove_var("API_TOKENS");
+        assert_eq!(store.scope_for("nonexistent"), None);
+    }
+}
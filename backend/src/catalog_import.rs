@@ -0,0 +1,535 @@
+use actix_multipart::Multipart;
+use actix_web::{web, HttpResponse};
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use serde::Serialize;
+
+use crate::satellite_service::{OrbitalElements, Satellite};
+use crate::AppState;
+
+/// Outcome of importing a single element set (one TLE pair/triplet, or one
+/// OMM record).
+#[derive(Debug, Serialize)]
+struct ImportLineError {
+    object: String,
+    error: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ImportSummary {
+    added: usize,
+    updated: usize,
+    rejected: usize,
+    errors: Vec<ImportLineError>,
+}
+
+/// Largest total upload we'll buffer into memory across every field of a
+/// single request. This endpoint requires a `catalog_admin`-scoped bearer
+/// token, but an authenticated caller must still not be able to exhaust
+/// the process's memory by sending many fields each just under the cap —
+/// the limit is tracked as a running total across the whole multipart
+/// payload, not reset per field.
+const MAX_UPLOAD_BYTES: usize = 10 * 1024 * 1024;
+
+/// `POST /api/satellites/import` — accepts a multipart file upload
+/// containing either a TLE two/three-line element file or a CCSDS OMM
+/// document, parses it into the service's satellite representation, and
+/// merges the result into the shared catalog.
+pub async fn import_catalog(
+    data: web::Data<AppState>,
+    mut payload: Multipart,
+) -> actix_web::Result<HttpResponse> {
+    let mut summary = ImportSummary {
+        added: 0,
+        updated: 0,
+        rejected: 0,
+        errors: Vec::new(),
+    };
+
+    let mut total_bytes = 0usize;
+
+    while let Some(mut field) = payload.try_next().await? {
+        let filename = field
+            .content_disposition()
+            .get_filename()
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| "upload".to_string());
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = field.try_next().await? {
+            total_bytes += chunk.len();
+            if total_bytes > MAX_UPLOAD_BYTES {
+                return Ok(HttpResponse::PayloadTooLarge().json(serde_json::json!({
+                    "error": format!("upload exceeds the {MAX_UPLOAD_BYTES}-byte limit")
+                })));
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+
+        let text = match std::str::from_utf8(&bytes) {
+            Ok(text) => text,
+            Err(_) => {
+                summary.rejected += 1;
+                summary.errors.push(ImportLineError {
+                    object: filename,
+                    error: "file is not valid UTF-8".to_string(),
+                });
+                continue;
+            }
+        };
+
+        let satellites = if looks_like_omm_json(text) {
+            parse_omm_json(text)
+        } else if looks_like_omm_xml(text) {
+            parse_omm_xml(text)
+        } else {
+            parse_tle(text)
+        };
+
+        {
+            let mut service = data.satellite_service.lock().unwrap();
+            for outcome in satellites {
+                match outcome {
+                    Ok(satellite) => {
+                        if service.upsert_satellite(satellite).is_some() {
+                            summary.updated += 1;
+                        } else {
+                            summary.added += 1;
+                        }
+                    }
+                    Err((object, error)) => {
+                        summary.rejected += 1;
+                        summary.errors.push(ImportLineError { object, error });
+                    }
+                }
+            }
+        }
+    }
+
+    let catalog = data.satellite_service.lock().unwrap().get_all_satellites();
+    if let Err(e) = data.catalog_store.save_catalog(&catalog).await {
+        summary.errors.push(ImportLineError {
+            object: "catalog".to_string(),
+            error: format!("failed to persist catalog: {e}"),
+        });
+    }
+    data.catalog_generation.bump();
+
+    Ok(HttpResponse::Ok().json(summary))
+}
+
+fn looks_like_omm_json(text: &str) -> bool {
+    text.trim_start().starts_with('{') || text.trim_start().starts_with('[')
+}
+
+fn looks_like_omm_xml(text: &str) -> bool {
+    text.trim_start().starts_with("<?xml") || text.trim_start().starts_with("<omm") || text.trim_start().starts_with("<ndm")
+}
+
+type ParsedSatellite = Result<Satellite, (String, String)>;
+
+/// Parses a classic two-line (or three-line, with a leading name line) TLE
+/// file containing one or more element sets.
+fn parse_tle(text: &str) -> Vec<ParsedSatellite> {
+    let lines: Vec<&str> = text.lines().map(|l| l.trim_end()).filter(|l| !l.is_empty()).collect();
+    let mut results = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let (name, line1, line2) = if lines[i].starts_with('1') && lines.get(i + 1).is_some_and(|l| l.starts_with('2')) {
+            // `lines[i]` is only known to start with '1' here; it may still be
+            // shorter than a real TLE line 1, so don't slice it by fixed
+            // column offsets yet. `parse_tle_pair`'s own length check below is
+            // what actually rejects a too-short line.
+            let candidate_name = lines[i]
+                .get(2..7)
+                .map(|id| format!("NORAD-{}", id.trim()))
+                .unwrap_or_else(|| format!("line {}", i + 1));
+            let pair = (candidate_name, lines[i], lines[i + 1]);
+            i += 2;
+            pair
+        } else if lines.get(i + 1).is_some_and(|l| l.starts_with('1'))
+            && lines.get(i + 2).is_some_and(|l| l.starts_with('2'))
+        {
+            let triplet = (lines[i].trim_start_matches('0').trim().to_string(), lines[i + 1], lines[i + 2]);
+            i += 3;
+            triplet
+        } else {
+            results.push(Err((
+                format!("line {}", i + 1),
+                "expected a TLE line pair starting with '1 ' / '2 '".to_string(),
+            )));
+            i += 1;
+            continue;
+        };
+
+        results.push(parse_tle_pair(&name, line1, line2));
+    }
+
+    results
+}
+
+fn parse_tle_pair(name: &str, line1: &str, line2: &str) -> ParsedSatellite {
+    if !checksum_ok(line1) || !checksum_ok(line2) {
+        return Err((name.to_string(), "TLE checksum mismatch".to_string()));
+    }
+    if line1.len() < 69 || line2.len() < 69 {
+        return Err((name.to_string(), "TLE line too short".to_string()));
+    }
+    // The field slices below use fixed byte offsets from the TLE column
+    // spec. `checksum_ok` treats any non-ASCII-digit character as
+    // contributing 0 to the checksum, so a multi-byte UTF-8 character can
+    // still pass it while straddling one of those offsets and panicking on
+    // a non-char-boundary slice. Rule that out up front.
+    if !line1.is_ascii() || !line2.is_ascii() {
+        return Err((name.to_string(), "TLE line contains non-ASCII characters".to_string()));
+    }
+
+    let norad_id: u32 = line1[2..7]
+        .trim()
+        .parse()
+        .map_err(|_| (name.to_string(), "invalid NORAD catalog number".to_string()))?;
+
+    let epoch = parse_tle_epoch(&line1[18..32]).map_err(|e| (name.to_string(), e))?;
+
+    let inclination_deg: f64 = line2[8..16]
+        .trim()
+        .parse()
+        .map_err(|_| (name.to_string(), "invalid inclination".to_string()))?;
+    let raan_deg: f64 = line2[17..25]
+        .trim()
+        .parse()
+        .map_err(|_| (name.to_string(), "invalid RAAN".to_string()))?;
+    let eccentricity: f64 = format!("0.{}", line2[26..33].trim())
+        .parse()
+        .map_err(|_| (name.to_string(), "invalid eccentricity".to_string()))?;
+    let arg_perigee_deg: f64 = line2[34..42]
+        .trim()
+        .parse()
+        .map_err(|_| (name.to_string(), "invalid argument of perigee".to_string()))?;
+    let mean_anomaly_deg: f64 = line2[43..51]
+        .trim()
+        .parse()
+        .map_err(|_| (name.to_string(), "invalid mean anomaly".to_string()))?;
+    let mean_motion_rev_per_day: f64 = line2[52..63]
+        .trim()
+        .parse()
+        .map_err(|_| (name.to_string(), "invalid mean motion".to_string()))?;
+
+    let mean_motion_rad_s = mean_motion_rev_per_day * 2.0 * std::f64::consts::PI / 86_400.0;
+    let semi_major_axis_km = (crate::satellite_service::EARTH_MU / mean_motion_rad_s.powi(2)).cbrt();
+
+    let elements = OrbitalElements {
+        semi_major_axis_km,
+        eccentricity,
+        inclination_deg,
+        raan_deg,
+        arg_perigee_deg,
+        mean_anomaly_deg,
+        epoch,
+    };
+
+    Ok(Satellite::from_elements(
+        format!("norad-{norad_id}"),
+        name.to_string(),
+        norad_id,
+        elements,
+    ))
+}
+
+fn checksum_ok(line: &str) -> bool {
+    let Some(expected) = line.chars().last().and_then(|c| c.to_digit(10)) else {
+        return false;
+    };
+    let body = &line[..line.len() - 1];
+
+    let sum: u32 = body
+        .chars()
+        .map(|c| match c {
+            '-' => 1,
+            c if c.is_ascii_digit() => c.to_digit(10).unwrap(),
+            _ => 0,
+        })
+        .sum();
+
+    sum % 10 == expected
+}
+
+/// TLE epoch format: two-digit year followed by fractional day-of-year,
+/// e.g. `24275.50000000`.
+fn parse_tle_epoch(field: &str) -> Result<DateTime<Utc>, String> {
+    let field = field.trim();
+    if field.len() < 5 {
+        return Err("invalid epoch field".to_string());
+    }
+
+    let year: i32 = field[0..2].parse().map_err(|_| "invalid epoch year".to_string())?;
+    let full_year = if year < 57 { 2000 + year } else { 1900 + year };
+    let day_of_year: f64 = field[2..].parse().map_err(|_| "invalid epoch day".to_string())?;
+
+    let jan1 = DateTime::parse_from_rfc3339(&format!("{full_year}-01-01T00:00:00Z"))
+        .map_err(|_| "invalid epoch year".to_string())?
+        .with_timezone(&Utc);
+
+    Ok(jan1 + chrono::Duration::milliseconds(((day_of_year - 1.0) * 86_400_000.0) as i64))
+}
+
+/// Minimal CCSDS OMM (JSON) parser covering the mean-Keplerian-elements
+/// fields the service needs; one JSON object or an array of objects.
+fn parse_omm_json(text: &str) -> Vec<ParsedSatellite> {
+    let value: serde_json::Value = match serde_json::from_str(text) {
+        Ok(value) => value,
+        Err(e) => return vec![Err(("omm".to_string(), format!("invalid JSON: {e}")))],
+    };
+
+    let records: Vec<serde_json::Value> = match value {
+        serde_json::Value::Array(items) => items,
+        other => vec![other],
+    };
+
+    records.iter().map(parse_omm_record).collect()
+}
+
+fn parse_omm_record(record: &serde_json::Value) -> ParsedSatellite {
+    let object_name = record
+        .get("OBJECT_NAME")
+        .and_then(|v| v.as_str())
+        .unwrap_or("UNKNOWN")
+        .to_string();
+
+    let field = |key: &str| -> Result<f64, (String, String)> {
+        record
+            .get(key)
+            .and_then(|v| v.as_str().and_then(|s| s.parse().ok()).or_else(|| v.as_f64()))
+            .ok_or_else(|| (object_name.clone(), format!("missing or invalid {key}")))
+    };
+
+    let norad_id = record
+        .get("NORAD_CAT_ID")
+        .and_then(|v| v.as_u64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+        .ok_or_else(|| (object_name.clone(), "missing NORAD_CAT_ID".to_string()))? as u32;
+
+    let epoch_str = record
+        .get("EPOCH")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| (object_name.clone(), "missing EPOCH".to_string()))?;
+    let epoch = DateTime::parse_from_rfc3339(&format!("{epoch_str}Z"))
+        .or_else(|_| DateTime::parse_from_rfc3339(epoch_str))
+        .map_err(|_| (object_name.clone(), "invalid EPOCH".to_string()))?
+        .with_timezone(&Utc);
+
+    let mean_motion_rev_per_day = field("MEAN_MOTION")?;
+    let mean_motion_rad_s = mean_motion_rev_per_day * 2.0 * std::f64::consts::PI / 86_400.0;
+    let semi_major_axis_km = (crate::satellite_service::EARTH_MU / mean_motion_rad_s.powi(2)).cbrt();
+
+    let elements = OrbitalElements {
+        semi_major_axis_km,
+        eccentricity: field("ECCENTRICITY")?,
+        inclination_deg: field("INCLINATION")?,
+        raan_deg: field("RA_OF_ASC_NODE")?,
+        arg_perigee_deg: field("ARG_OF_PERICENTER")?,
+        mean_anomaly_deg: field("MEAN_ANOMALY")?,
+        epoch,
+    };
+
+    Ok(Satellite::from_elements(
+        format!("norad-{norad_id}"),
+        object_name,
+        norad_id,
+        elements,
+    ))
+}
+
+/// Minimal CCSDS OMM (XML) parser covering the same mean-Keplerian-elements
+/// fields as `parse_omm_json`. This is a flat tag-text extractor, not a
+/// general XML parser: it doesn't validate well-formedness, namespaces, or
+/// attributes, and assumes the fields it looks for aren't nested inside a
+/// same-named tag. That's enough for the CCSDS OMM XML schema, where each
+/// field tag appears once per `<segment>`, however deeply it is nested
+/// inside `<metadata>`/`<data>`.
+fn parse_omm_xml(text: &str) -> Vec<ParsedSatellite> {
+    let segments: Vec<&str> = match text.find("<segment") {
+        Some(first) => text[first..]
+            .split("<segment")
+            .filter(|s| !s.trim().is_empty())
+            .collect(),
+        None => vec![text],
+    };
+
+    segments.iter().map(|segment| parse_omm_xml_segment(segment)).collect()
+}
+
+/// Finds the text content of the first `<tag>...</tag>` (or `<tag attr="...">...</tag>`)
+/// element in `xml`. Doesn't handle nested elements of the same name.
+fn extract_xml_tag<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let open_start = xml.find(&format!("<{tag}"))?;
+    let open_end = xml[open_start..].find('>')? + open_start + 1;
+    let close_tag = format!("</{tag}>");
+    let close_start = xml[open_end..].find(&close_tag)? + open_end;
+    Some(xml[open_end..close_start].trim())
+}
+
+fn parse_omm_xml_segment(segment: &str) -> ParsedSatellite {
+    let object_name = extract_xml_tag(segment, "OBJECT_NAME")
+        .unwrap_or("UNKNOWN")
+        .to_string();
+
+    let field = |tag: &str| -> Result<f64, (String, String)> {
+        extract_xml_tag(segment, tag)
+            .and_then(|v| v.parse().ok())
+            .ok_or_else(|| (object_name.clone(), format!("missing or invalid {tag}")))
+    };
+
+    let norad_id: u32 = extract_xml_tag(segment, "NORAD_CAT_ID")
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| (object_name.clone(), "missing NORAD_CAT_ID".to_string()))?;
+
+    let epoch_str = extract_xml_tag(segment, "EPOCH")
+        .ok_or_else(|| (object_name.clone(), "missing EPOCH".to_string()))?;
+    let epoch = DateTime::parse_from_rfc3339(&format!("{epoch_str}Z"))
+        .or_else(|_| DateTime::parse_from_rfc3339(epoch_str))
+        .map_err(|_| (object_name.clone(), "invalid EPOCH".to_string()))?
+        .with_timezone(&Utc);
+
+    let mean_motion_rev_per_day = field("MEAN_MOTION")?;
+    let mean_motion_rad_s = mean_motion_rev_per_day * 2.0 * std::f64::consts::PI / 86_400.0;
+    let semi_major_axis_km = (crate::satellite_service::EARTH_MU / mean_motion_rad_s.powi(2)).cbrt();
+
+    let elements = OrbitalElements {
+        semi_major_axis_km,
+        eccentricity: field("ECCENTRICITY")?,
+        inclination_deg: field("INCLINATION")?,
+        raan_deg: field("RA_OF_ASC_NODE")?,
+        arg_perigee_deg: field("ARG_OF_PERICENTER")?,
+        mean_anomaly_deg: field("MEAN_ANOMALY")?,
+        epoch,
+    };
+
+    Ok(Satellite::from_elements(
+        format!("norad-{norad_id}"),
+        object_name,
+        norad_id,
+        elements,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_LINE1: &str = "1 25544U 98067A   24275.50000000  .00016717  00000-0  10270-3 0  9990";
+    const VALID_LINE2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.49512254  9994";
+
+    #[test]
+    fn checksum_ok_accepts_valid_lines() {
+        assert!(checksum_ok(VALID_LINE1));
+        assert!(checksum_ok(VALID_LINE2));
+    }
+
+    #[test]
+    fn checksum_ok_rejects_tampered_line() {
+        let mut tampered = VALID_LINE1.to_string();
+        tampered.pop();
+        tampered.push('5'); // VALID_LINE1's real checksum digit is '0'
+        assert!(!checksum_ok(&tampered));
+    }
+
+    #[test]
+    fn checksum_ok_rejects_garbage_input() {
+        assert!(!checksum_ok(""));
+        assert!(!checksum_ok("not a tle line"));
+    }
+
+    #[test]
+    fn parse_tle_parses_a_valid_two_line_pair() {
+        let text = format!("{VALID_LINE1}\n{VALID_LINE2}\n");
+        let results = parse_tle(&text);
+        assert_eq!(results.len(), 1);
+        let satellite = results[0].as_ref().expect("valid TLE pair should parse");
+        assert_eq!(satellite.norad_id, 25544);
+    }
+
+    #[test]
+    fn parse_tle_rejects_truncated_lines_without_panicking() {
+        // A two-line "pair" that satisfies the '1'/'2' prefix check but is
+        // far too short to contain real TLE fields; must be reported as an
+        // error, not panic on an out-of-bounds fixed-column slice.
+        let text = "1\n2\n";
+        let results = parse_tle(text);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
+
+    #[test]
+    fn parse_tle_rejects_garbage_text() {
+        let text = "this is not a TLE file at all\njust some prose\n";
+        let results = parse_tle(text);
+        assert!(results.iter().all(|r| r.is_err()));
+    }
+
+    const VALID_OMM_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ndm>
+  <omm id="CCSDS_OMM_VERS" version="2.0">
+    <body>
+      <segment>
+        <metadata>
+          <OBJECT_NAME>ISS (ZARYA)</OBJECT_NAME>
+          <OBJECT_ID>1998-067A</OBJECT_ID>
+        </metadata>
+        <data>
+          <meanElements>
+            <EPOCH>2026-01-01T00:00:00</EPOCH>
+            <MEAN_MOTION>15.49512254</MEAN_MOTION>
+            <ECCENTRICITY>0.0006703</ECCENTRICITY>
+            <INCLINATION>51.6416</INCLINATION>
+            <RA_OF_ASC_NODE>247.4627</RA_OF_ASC_NODE>
+            <ARG_OF_PERICENTER>130.5360</ARG_OF_PERICENTER>
+            <MEAN_ANOMALY>325.0288</MEAN_ANOMALY>
+          </meanElements>
+          <additionalParameters>
+            <NORAD_CAT_ID>25544</NORAD_CAT_ID>
+          </additionalParameters>
+        </data>
+      </segment>
+    </body>
+  </omm>
+</ndm>"#;
+
+    #[test]
+    fn parse_omm_xml_parses_a_valid_segment() {
+        let results = parse_omm_xml(VALID_OMM_XML);
+        assert_eq!(results.len(), 1);
+        let satellite = results[0].as_ref().expect("valid OMM/XML segment should parse");
+        assert_eq!(satellite.norad_id, 25544);
+        assert_eq!(satellite.name, "ISS (ZARYA)");
+    }
+
+    #[test]
+    fn parse_omm_xml_reports_missing_required_field() {
+        let text = VALID_OMM_XML.replace("<NORAD_CAT_ID>25544</NORAD_CAT_ID>", "");
+        let results = parse_omm_xml(&text);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
+
+    #[test]
+    fn parse_omm_xml_parses_multiple_segments() {
+        let text = format!(
+            "<ndm><body>{0}{1}</body></ndm>",
+            VALID_OMM_XML
+                .split("<segment")
+                .nth(1)
+                .map(|s| format!("<segment{s}"))
+                .unwrap(),
+            VALID_OMM_XML
+                .split("<segment")
+                .nth(1)
+                .map(|s| format!("<segment{}", s.replace("25544", "20580").replace("ISS (ZARYA)", "HST")))
+                .unwrap(),
+        );
+        let results = parse_omm_xml(&text);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().norad_id, 25544);
+        assert_eq!(results[1].as_ref().unwrap().norad_id, 20580);
+    }
+}
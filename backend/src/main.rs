@@ -1,20 +1,33 @@
+mod auth;
+mod catalog_import;
+mod metrics;
+mod position_stream;
 mod satellite_service;
+mod storage;
 
 use actix_cors::Cors;
 use actix_files::Files;
-use actix_web::{middleware::Logger, web, App, HttpResponse, HttpServer, Result};
+use actix_web::{middleware::Logger, web, App, HttpRequest, HttpResponse, HttpServer, Result};
 use serde::Serialize;
 use std::env;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::sync::broadcast;
 use tokio::time::{interval, Duration};
 use tracing::{info, warn, Level};
 use tracing_subscriber::FmtSubscriber;
 use webbrowser;
 
+use auth::{BearerAuth, TokenStore};
+use metrics::{Metrics, RequestMetrics};
+use position_stream::{PositionDelta, PositionSender, BROADCAST_CAPACITY};
+use storage::{CatalogStore, NewReservation, ReservationStore, StorageError};
+
 #[cfg(feature = "embed_frontend")]
 use {
-    actix_web::{http::header, HttpRequest},
+    actix_web::http::header,
     mime_guess::from_path,
     rust_embed::RustEmbed,
 };
@@ -24,11 +37,49 @@ use {
 #[folder = "../frontend/dist"]
 struct EmbeddedDist;
 
-use satellite_service::SatelliteService;
+use satellite_service::{ConjunctionAnalysisRequest, SatelliteService};
 
 #[derive(Clone)]
 pub struct AppState {
     pub satellite_service: Arc<Mutex<SatelliteService>>,
+    pub position_sender: PositionSender,
+    pub metrics: Metrics,
+    pub catalog_store: Arc<dyn CatalogStore>,
+    pub reservation_store: Arc<dyn ReservationStore>,
+    pub catalog_generation: Arc<CatalogGeneration>,
+}
+
+/// Tracks when `/api/satellites` last changed, so `get_satellites` can
+/// answer conditional requests without re-hashing the catalog payload.
+/// Bumped once per `start_position_updater` cycle and after each catalog
+/// import.
+pub struct CatalogGeneration {
+    generation: AtomicU64,
+    last_modified_millis: AtomicI64,
+}
+
+impl CatalogGeneration {
+    fn new() -> Self {
+        Self {
+            generation: AtomicU64::new(0),
+            last_modified_millis: AtomicI64::new(chrono::Utc::now().timestamp_millis()),
+        }
+    }
+
+    pub(crate) fn bump(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.last_modified_millis
+            .store(chrono::Utc::now().timestamp_millis(), Ordering::SeqCst);
+    }
+
+    fn etag(&self) -> String {
+        format!("\"{}\"", self.generation.load(Ordering::SeqCst))
+    }
+
+    fn last_modified(&self) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::from_timestamp_millis(self.last_modified_millis.load(Ordering::SeqCst))
+            .unwrap_or_else(chrono::Utc::now)
+    }
 }
 
 #[derive(Serialize)]
@@ -99,35 +150,112 @@ async fn api_info() -> Result<HttpResponse> {
                 path: "/api/satellites/create-reservation".to_string(),
                 description: "Create orbit reservation".to_string(),
             },
+            ApiEndpoint {
+                method: "GET".to_string(),
+                path: "/api/satellites/stream".to_string(),
+                description: "WebSocket stream of live satellite position updates".to_string(),
+            },
+            ApiEndpoint {
+                method: "GET".to_string(),
+                path: "/metrics".to_string(),
+                description: "Prometheus metrics".to_string(),
+            },
+            ApiEndpoint {
+                method: "POST".to_string(),
+                path: "/api/satellites/import".to_string(),
+                description: "Import a TLE or CCSDS OMM (JSON or XML) catalog file".to_string(),
+            },
         ],
     };
 
     Ok(HttpResponse::Ok().json(info))
 }
 
+/// Cadence of `start_position_updater`; also used as the `Cache-Control`
+/// max-age for `/api/satellites` since the payload cannot change any
+/// faster than that.
+const POSITION_UPDATE_INTERVAL_SECS: u64 = 30;
+
 // Satellite API routes
-async fn get_satellites(data: web::Data<AppState>) -> Result<HttpResponse> {
+async fn get_satellites(req: HttpRequest, data: web::Data<AppState>) -> Result<HttpResponse> {
+    let etag = data.catalog_generation.etag();
+    let last_modified = data.catalog_generation.last_modified();
+
+    if request_is_fresh(&req, &etag, last_modified) {
+        return Ok(HttpResponse::NotModified()
+            .insert_header((actix_web::http::header::ETAG, etag))
+            .insert_header((actix_web::http::header::CACHE_CONTROL, cache_control_header()))
+            .finish());
+    }
+
     let service = data.satellite_service.lock().unwrap();
     let satellites = service.get_all_satellites();
+    data.metrics.satellites_count.set(satellites.len() as i64);
 
     let response = serde_json::json!({
         "satellites": satellites,
         "count": satellites.len()
     });
 
-    Ok(HttpResponse::Ok().json(response))
+    Ok(HttpResponse::Ok()
+        .insert_header((actix_web::http::header::ETAG, etag))
+        .insert_header((actix_web::http::header::LAST_MODIFIED, format_http_date(last_modified)))
+        .insert_header((actix_web::http::header::CACHE_CONTROL, cache_control_header()))
+        .json(response))
+}
+
+fn cache_control_header() -> String {
+    format!("max-age={POSITION_UPDATE_INTERVAL_SECS}")
+}
+
+fn format_http_date(at: chrono::DateTime<chrono::Utc>) -> String {
+    at.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// Honors `If-None-Match` (exact ETag match) and, failing that,
+/// `If-Modified-Since` (catalog unchanged since the given time).
+fn request_is_fresh(req: &HttpRequest, etag: &str, last_modified: chrono::DateTime<chrono::Utc>) -> bool {
+    if let Some(if_none_match) = req.headers().get(actix_web::http::header::IF_NONE_MATCH) {
+        if let Ok(value) = if_none_match.to_str() {
+            if value.split(',').any(|candidate| candidate.trim() == etag) {
+                return true;
+            }
+        }
+    }
+
+    if let Some(if_modified_since) = req.headers().get(actix_web::http::header::IF_MODIFIED_SINCE) {
+        if let Ok(value) = if_modified_since.to_str() {
+            if let Ok(since) = chrono::DateTime::parse_from_rfc2822(value) {
+                return last_modified.timestamp() <= since.with_timezone(&chrono::Utc).timestamp();
+            }
+        }
+    }
+
+    false
 }
 
 async fn analyze_conjunctions(
     data: web::Data<AppState>,
-    _req: web::Json<serde_json::Value>,
+    req: web::Json<ConjunctionAnalysisRequest>,
 ) -> Result<HttpResponse> {
-    let _service = data.satellite_service.lock().unwrap();
-    // Placeholder for conjunction analysis - will implement based on request data
+    if let Err(message) = req.validate() {
+        data.metrics
+            .conjunction_requests_total
+            .with_label_values(&["invalid"])
+            .inc();
+        return Ok(HttpResponse::BadRequest().json(serde_json::json!({ "error": message })));
+    }
+
+    let service = data.satellite_service.lock().unwrap();
+    let conjunctions = service.analyze_conjunctions(&req);
+    data.metrics
+        .conjunction_requests_total
+        .with_label_values(&["ok"])
+        .inc();
+
     let analysis = serde_json::json!({
-        "conjunctions": [],
+        "conjunctions": conjunctions,
         "analysis_time": chrono::Utc::now(),
-        "message": "Conjunction analysis feature coming soon"
     });
 
     Ok(HttpResponse::Ok().json(analysis))
@@ -135,24 +263,44 @@ async fn analyze_conjunctions(
 
 async fn create_reservation(
     data: web::Data<AppState>,
-    _req: web::Json<serde_json::Value>,
+    req: web::Json<NewReservation>,
 ) -> Result<HttpResponse> {
-    let _service = data.satellite_service.lock().unwrap();
-    // Placeholder for reservation creation - will implement based on request data
-    let result = serde_json::json!({
-        "reservation_id": "res_123456",
-        "status": "created",
-        "message": "Reservation created successfully"
-    });
-
-    Ok(HttpResponse::Ok().json(result))
+    match data.reservation_store.create_reservation(req.into_inner()).await {
+        Ok(reservation) => {
+            data.metrics
+                .reservation_requests_total
+                .with_label_values(&["created"])
+                .inc();
+            Ok(HttpResponse::Ok().json(reservation))
+        }
+        Err(StorageError::Conflict(message)) => {
+            data.metrics
+                .reservation_requests_total
+                .with_label_values(&["conflict"])
+                .inc();
+            Ok(HttpResponse::Conflict().json(serde_json::json!({ "error": message })))
+        }
+        Err(StorageError::Backend(message)) => {
+            data.metrics
+                .reservation_requests_total
+                .with_label_values(&["error"])
+                .inc();
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({ "error": message })))
+        }
+    }
 }
 
-async fn start_position_updater(satellite_service: Arc<Mutex<SatelliteService>>) {
-    let mut interval = interval(Duration::from_secs(30)); // Update every 30 seconds
+async fn start_position_updater(
+    satellite_service: Arc<Mutex<SatelliteService>>,
+    position_sender: PositionSender,
+    metrics: Metrics,
+    catalog_generation: Arc<CatalogGeneration>,
+) {
+    let mut interval = interval(Duration::from_secs(POSITION_UPDATE_INTERVAL_SECS));
 
     loop {
         interval.tick().await;
+        let cycle_started_at = Instant::now();
 
         let satellites = {
             satellite_service
@@ -164,6 +312,14 @@ async fn start_position_updater(satellite_service: Arc<Mutex<SatelliteService>>)
             service.get_all_satellites()
         };
 
+        let deltas: Vec<PositionDelta> = satellites.iter().map(PositionDelta::from).collect();
+        // Errors here just mean there are currently no subscribers.
+        let _ = position_sender.send(deltas);
+
+        catalog_generation.bump();
+        metrics.satellites_count.set(satellites.len() as i64);
+        metrics.observe_updater_cycle(cycle_started_at, "ok");
+
         info!("Updated positions for {} satellites", satellites.len());
     }
 }
@@ -185,6 +341,48 @@ async fn embedded_frontend_handler(req: HttpRequest) -> Result<HttpResponse> {
     }
 }
 
+/// Builds the catalog/reservation stores named by `STORAGE_BACKEND`
+/// (`memory` by default). `file` keeps JSON documents under
+/// `STORAGE_FILE_DIR` (default `./data`); `sqlite` opens `DATABASE_URL`
+/// (default `sqlite://orbitalos.db`).
+async fn init_storage() -> (Arc<dyn CatalogStore>, Arc<dyn ReservationStore>) {
+    let backend = env::var("STORAGE_BACKEND").unwrap_or_else(|_| "memory".to_string());
+
+    match backend.as_str() {
+        "file" => {
+            let dir = env::var("STORAGE_FILE_DIR").unwrap_or_else(|_| "./data".to_string());
+            let catalog = storage::file::FileCatalogStore::new(PathBuf::from(&dir).join("catalog.json"));
+            let reservations =
+                storage::file::FileReservationStore::new(PathBuf::from(&dir).join("reservations.json"));
+            (
+                Arc::new(catalog) as Arc<dyn CatalogStore>,
+                Arc::new(reservations) as Arc<dyn ReservationStore>,
+            )
+        }
+        "sqlite" => {
+            let database_url = env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite://orbitalos.db".to_string());
+            match storage::sqlite::SqliteStore::connect(&database_url).await {
+                Ok(store) => {
+                    let store = Arc::new(store);
+                    (store.clone() as Arc<dyn CatalogStore>, store as Arc<dyn ReservationStore>)
+                }
+                Err(e) => {
+                    warn!("Failed to connect to sqlite storage ({e}); falling back to in-memory storage");
+                    memory_storage()
+                }
+            }
+        }
+        _ => memory_storage(),
+    }
+}
+
+fn memory_storage() -> (Arc<dyn CatalogStore>, Arc<dyn ReservationStore>) {
+    (
+        Arc::new(storage::memory::MemoryCatalogStore::new()),
+        Arc::new(storage::memory::MemoryReservationStore::new()),
+    )
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     // Initialize logging
@@ -204,10 +402,23 @@ async fn main() -> std::io::Result<()> {
 
     info!("Initializing satellite service...");
 
-    // Load initial satellite data
+    // Select the storage backend and, for the durable backends, restore any
+    // previously imported catalog over the built-in defaults.
+    let (catalog_store, reservation_store) = init_storage().await;
+
+    match catalog_store.load_catalog().await {
+        Ok(satellites) if !satellites.is_empty() => {
+            let mut service = satellite_service.lock().unwrap();
+            for satellite in satellites {
+                service.upsert_satellite(satellite);
+            }
+        }
+        Ok(_) => {}
+        Err(e) => warn!("Failed to load persisted catalog: {e}"),
+    }
+
     {
         let service = satellite_service.lock().unwrap();
-        // Service is initialized with default data in new()
         info!(
             "Satellite service initialized with {} satellites",
             service.get_all_satellites().len()
@@ -217,15 +428,28 @@ async fn main() -> std::io::Result<()> {
     info!("Satellite service initialized with satellite data");
 
     // Start background position updater
+    let (position_sender, _) = broadcast::channel(BROADCAST_CAPACITY);
+    let metrics = Metrics::new();
+    let catalog_generation = Arc::new(CatalogGeneration::new());
     let updater_service = Arc::clone(&satellite_service);
+    let updater_sender = position_sender.clone();
+    let updater_metrics = metrics.clone();
+    let updater_generation = Arc::clone(&catalog_generation);
     tokio::spawn(async move {
-        start_position_updater(updater_service).await;
+        start_position_updater(updater_service, updater_sender, updater_metrics, updater_generation).await;
     });
 
     let app_state = AppState {
         satellite_service: Arc::clone(&satellite_service),
+        position_sender,
+        metrics,
+        catalog_store,
+        reservation_store,
+        catalog_generation,
     };
 
+    let token_store = TokenStore::from_env();
+
     let exe_dir = std::env::current_exe()
         .ok()
         .and_then(|path| path.parent().map(|p| p.to_path_buf()))
@@ -250,6 +474,7 @@ async fn main() -> std::io::Result<()> {
     );
 
     let static_dir_for_server = static_dir.clone();
+    let token_store_for_server = token_store.clone();
     let bind_host = host.clone();
     let browser_host = match host.as_str() {
         "0.0.0.0" | "127.0.0.1" => "localhost".to_string(),
@@ -269,18 +494,34 @@ async fn main() -> std::io::Result<()> {
 
         let mut app = App::new()
             .app_data(web::Data::new(app_state.clone()))
+            .app_data(web::Data::new(app_state.position_sender.clone()))
+            .app_data(web::Data::new(app_state.metrics.clone()))
+            .app_data(web::Data::new(token_store_for_server.clone()))
             .wrap(Logger::default())
+            .wrap(RequestMetrics)
             .wrap(cors)
             .route("/health", web::get().to(health_check))
             .route("/api/info", web::get().to(api_info))
+            .route("/metrics", web::get().to(metrics::metrics_handler))
             .route("/api/satellites", web::get().to(get_satellites))
-            .route(
-                "/api/satellites/conjunction-analysis",
-                web::post().to(analyze_conjunctions),
+            .service(
+                web::resource("/api/satellites/conjunction-analysis")
+                    .wrap(BearerAuth::read_only())
+                    .route(web::post().to(analyze_conjunctions)),
+            )
+            .service(
+                web::resource("/api/satellites/create-reservation")
+                    .wrap(BearerAuth::reservation_create())
+                    .route(web::post().to(create_reservation)),
             )
             .route(
-                "/api/satellites/create-reservation",
-                web::post().to(create_reservation),
+                "/api/satellites/stream",
+                web::get().to(position_stream::stream_positions),
+            )
+            .service(
+                web::resource("/api/satellites/import")
+                    .wrap(BearerAuth::catalog_admin())
+                    .route(web::post().to(catalog_import::import_catalog)),
             );
 
         let static_assets = static_dir_for_server.clone();
@@ -318,3 +559,67 @@ async fn main() -> std::io::Result<()> {
 
     server.await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    fn epoch() -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc)
+    }
+
+    #[test]
+    fn request_is_fresh_matches_exact_if_none_match() {
+        let req = TestRequest::default()
+            .insert_header((actix_web::http::header::IF_NONE_MATCH, "\"abc123\""))
+            .to_http_request();
+        assert!(request_is_fresh(&req, "\"abc123\"", epoch()));
+    }
+
+    #[test]
+    fn request_is_fresh_matches_one_of_multiple_if_none_match_values() {
+        let req = TestRequest::default()
+            .insert_header((actix_web::http::header::IF_NONE_MATCH, "\"other\", \"abc123\""))
+            .to_http_request();
+        assert!(request_is_fresh(&req, "\"abc123\"", epoch()));
+    }
+
+    #[test]
+    fn request_is_fresh_rejects_non_matching_if_none_match() {
+        let req = TestRequest::default()
+            .insert_header((actix_web::http::header::IF_NONE_MATCH, "\"different\""))
+            .to_http_request();
+        assert!(!request_is_fresh(&req, "\"abc123\"", epoch()));
+    }
+
+    #[test]
+    fn request_is_fresh_honors_if_modified_since_when_unchanged() {
+        let req = TestRequest::default()
+            .insert_header((
+                actix_web::http::header::IF_MODIFIED_SINCE,
+                format_http_date(epoch()),
+            ))
+            .to_http_request();
+        assert!(request_is_fresh(&req, "\"abc123\"", epoch()));
+    }
+
+    #[test]
+    fn request_is_fresh_rejects_stale_if_modified_since() {
+        let req = TestRequest::default()
+            .insert_header((
+                actix_web::http::header::IF_MODIFIED_SINCE,
+                format_http_date(epoch() - chrono::Duration::seconds(60)),
+            ))
+            .to_http_request();
+        assert!(!request_is_fresh(&req, "\"abc123\"", epoch()));
+    }
+
+    #[test]
+    fn request_is_fresh_defaults_false_with_no_conditional_headers() {
+        let req = TestRequest::default().to_http_request();
+        assert!(!request_is_fresh(&req, "\"abc123\"", epoch()));
+    }
+}
@@ -0,0 +1,213 @@
+use std::future::{ready, Ready};
+use std::time::Instant;
+
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{web, Error, HttpResponse};
+use futures_util::future::LocalBoxFuture;
+use prometheus::{Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Registry, TextEncoder};
+
+/// Application-wide Prometheus registry plus the handles handlers use to
+/// record observations. Cloning is cheap: every metric is internally
+/// reference-counted by the `prometheus` crate.
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Registry,
+    pub http_requests_total: IntCounterVec,
+    pub http_request_duration_seconds: HistogramVec,
+    pub satellites_count: IntGauge,
+    pub updater_cycles_total: IntCounterVec,
+    pub updater_cycle_duration_seconds: HistogramVec,
+    pub websocket_subscribers: IntGauge,
+    pub reservation_requests_total: IntCounterVec,
+    pub conjunction_requests_total: IntCounterVec,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests_total = IntCounterVec::new(
+            prometheus::Opts::new("http_requests_total", "Total HTTP requests by endpoint and status"),
+            &["endpoint", "status"],
+        )
+        .unwrap();
+
+        let http_request_duration_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "HTTP request latency by endpoint",
+            ),
+            &["endpoint"],
+        )
+        .unwrap();
+
+        let satellites_count = IntGauge::new("satellites_count", "Number of satellites currently tracked").unwrap();
+
+        let updater_cycles_total = IntCounterVec::new(
+            prometheus::Opts::new(
+                "updater_cycles_total",
+                "Number of background position-update cycles run",
+            ),
+            &["result"],
+        )
+        .unwrap();
+
+        let updater_cycle_duration_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "updater_cycle_duration_seconds",
+                "Duration of each background position-update cycle",
+            ),
+            &["result"],
+        )
+        .unwrap();
+
+        let websocket_subscribers =
+            IntGauge::new("websocket_subscribers", "Active WebSocket position-stream subscribers").unwrap();
+
+        let reservation_requests_total = IntCounterVec::new(
+            prometheus::Opts::new("reservation_requests_total", "Reservation requests by outcome"),
+            &["outcome"],
+        )
+        .unwrap();
+
+        let conjunction_requests_total = IntCounterVec::new(
+            prometheus::Opts::new("conjunction_requests_total", "Conjunction analysis requests by outcome"),
+            &["outcome"],
+        )
+        .unwrap();
+
+        registry
+            .register(Box::new(http_requests_total.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(http_request_duration_seconds.clone()))
+            .unwrap();
+        registry.register(Box::new(satellites_count.clone())).unwrap();
+        registry
+            .register(Box::new(updater_cycles_total.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(updater_cycle_duration_seconds.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(websocket_subscribers.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(reservation_requests_total.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(conjunction_requests_total.clone()))
+            .unwrap();
+
+        Self {
+            registry,
+            http_requests_total,
+            http_request_duration_seconds,
+            satellites_count,
+            updater_cycles_total,
+            updater_cycle_duration_seconds,
+            websocket_subscribers,
+            reservation_requests_total,
+            conjunction_requests_total,
+        }
+    }
+
+    /// Times an updater cycle and records its duration and outcome under
+    /// `updater_cycles_total` / `updater_cycle_duration_seconds`.
+    pub fn observe_updater_cycle(&self, started_at: Instant, result: &str) {
+        let elapsed = started_at.elapsed().as_secs_f64();
+        self.updater_cycles_total.with_label_values(&[result]).inc();
+        self.updater_cycle_duration_seconds
+            .with_label_values(&[result])
+            .observe(elapsed);
+    }
+
+    pub fn record_http_request(&self, endpoint: &str, status: u16, elapsed_seconds: f64) {
+        self.http_requests_total
+            .with_label_values(&[endpoint, &status.to_string()])
+            .inc();
+        self.http_request_duration_seconds
+            .with_label_values(&[endpoint])
+            .observe(elapsed_seconds);
+    }
+}
+
+/// Actix middleware that times every request and records it under
+/// `http_requests_total` / `http_request_duration_seconds`, labelled by the
+/// matched route pattern (e.g. `/api/satellites/{id}`) rather than the raw
+/// path, so per-endpoint cardinality stays bounded. Requests that match no
+/// route share the single `unmatched` label.
+pub struct RequestMetrics;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestMetrics
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestMetricsMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestMetricsMiddleware { service }))
+    }
+}
+
+pub struct RequestMetricsMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestMetricsMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let metrics = req.app_data::<web::Data<Metrics>>().cloned();
+        let started_at = Instant::now();
+
+        let fut = self.service.call(req);
+        Box::pin(async move {
+            let res = fut.await?;
+            if let Some(metrics) = metrics {
+                // `match_pattern` only resolves once the request has been
+                // routed to a resource, which happens inside `self.service`
+                // (this middleware is wrapped at the `App` level, outside
+                // routing) — so it must be read off the response's request,
+                // not the request we were handed before dispatch.
+                let endpoint = res
+                    .request()
+                    .match_pattern()
+                    .unwrap_or_else(|| "unmatched".to_string());
+                metrics.record_http_request(&endpoint, res.status().as_u16(), started_at.elapsed().as_secs_f64());
+            }
+            Ok(res)
+        })
+    }
+}
+
+/// `GET /metrics` — renders the registry in the Prometheus text exposition
+/// format.
+pub async fn metrics_handler(metrics: web::Data<Metrics>) -> HttpResponse {
+    let encoder = TextEncoder::new();
+    let metric_families = metrics.registry.gather();
+
+    let mut buffer = Vec::new();
+    if encoder.encode(&metric_families, &mut buffer).is_err() {
+        return HttpResponse::InternalServerError().finish();
+    }
+
+    HttpResponse::Ok()
+        .content_type(encoder.format_type())
+        .body(buffer)
+}
@@ -0,0 +1,165 @@
+use actix_web::{web, Error, HttpRequest, HttpResponse};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::metrics::Metrics;
+use crate::satellite_service::Satellite;
+
+/// One satellite's state as pushed to subscribed clients after each
+/// position-updater cycle.
+#[derive(Debug, Clone, Serialize)]
+pub struct PositionDelta {
+    pub id: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude_km: f64,
+    pub velocity_km_s: f64,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<&Satellite> for PositionDelta {
+    fn from(satellite: &Satellite) -> Self {
+        Self {
+            id: satellite.id.clone(),
+            latitude: satellite.latitude,
+            longitude: satellite.longitude,
+            altitude_km: satellite.altitude_km,
+            velocity_km_s: satellite.velocity_km_s,
+            timestamp: satellite.last_updated,
+        }
+    }
+}
+
+/// Capacity of the broadcast channel feeding `/api/satellites/stream`.
+/// Sized generously above the update cadence so a slow client lags instead
+/// of disconnecting other subscribers.
+pub const BROADCAST_CAPACITY: usize = 256;
+
+pub type PositionSender = broadcast::Sender<Vec<PositionDelta>>;
+
+/// A bounding box filter, in degrees, expressed as (min_lat, min_lon,
+/// max_lat, max_lon).
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct BoundingBox {
+    pub min_lat: f64,
+    pub min_lon: f64,
+    pub max_lat: f64,
+    pub max_lon: f64,
+}
+
+impl BoundingBox {
+    fn contains(&self, delta: &PositionDelta) -> bool {
+        delta.latitude >= self.min_lat
+            && delta.latitude <= self.max_lat
+            && delta.longitude >= self.min_lon
+            && delta.longitude <= self.max_lon
+    }
+}
+
+/// Client -> server subscribe message, sent as JSON text over the socket.
+#[derive(Debug, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ClientMessage {
+    #[default]
+    Unknown,
+    Subscribe {
+        #[serde(default)]
+        satellite_ids: Option<Vec<String>>,
+        #[serde(default)]
+        bounding_box: Option<BoundingBox>,
+    },
+}
+
+#[derive(Default)]
+struct Subscription {
+    satellite_ids: Option<Vec<String>>,
+    bounding_box: Option<BoundingBox>,
+}
+
+impl Subscription {
+    fn matches(&self, delta: &PositionDelta) -> bool {
+        if let Some(ids) = &self.satellite_ids {
+            if !ids.iter().any(|id| id == &delta.id) {
+                return false;
+            }
+        }
+        if let Some(bbox) = &self.bounding_box {
+            if !bbox.contains(delta) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// `GET /api/satellites/stream` — upgrades to a WebSocket and forwards every
+/// position-updater broadcast to the client, filtered by whatever the
+/// client most recently asked to subscribe to.
+pub async fn stream_positions(
+    req: HttpRequest,
+    stream: web::Payload,
+    sender: web::Data<PositionSender>,
+    metrics: web::Data<Metrics>,
+) -> Result<HttpResponse, Error> {
+    let (response, mut session, mut msg_stream) = actix_ws::handle(&req, stream)?;
+    let mut updates = sender.subscribe();
+    metrics.websocket_subscribers.inc();
+
+    actix_web::rt::spawn(async move {
+        let metrics = metrics;
+        let mut subscription = Subscription::default();
+
+        loop {
+            tokio::select! {
+                update = updates.recv() => {
+                    let deltas = match update {
+                        Ok(deltas) => deltas,
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    };
+
+                    let filtered: Vec<&PositionDelta> = deltas
+                        .iter()
+                        .filter(|delta| subscription.matches(delta))
+                        .collect();
+
+                    if filtered.is_empty() {
+                        continue;
+                    }
+
+                    let payload = serde_json::json!({ "type": "positions", "satellites": filtered });
+                    if session.text(payload.to_string()).await.is_err() {
+                        break;
+                    }
+                }
+                msg = msg_stream.next() => {
+                    match msg {
+                        Some(Ok(actix_ws::Message::Text(text))) => {
+                            if let Ok(ClientMessage::Subscribe { satellite_ids, bounding_box }) =
+                                serde_json::from_str::<ClientMessage>(&text)
+                            {
+                                subscription = Subscription { satellite_ids, bounding_box };
+                            }
+                        }
+                        Some(Ok(actix_ws::Message::Close(reason))) => {
+                            let _ = session.close(reason).await;
+                            break;
+                        }
+                        Some(Ok(actix_ws::Message::Ping(bytes))) => {
+                            if session.pong(&bytes).await.is_err() {
+                                break;
+                            }
+                        }
+                        Some(Err(_)) | None => break,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        metrics.websocket_subscribers.dec();
+    });
+
+    Ok(response)
+}
@@ -0,0 +1,699 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Standard gravitational parameter of Earth, km^3/s^2.
+pub(crate) const EARTH_MU: f64 = 398_600.4418;
+/// Mean equatorial radius of Earth, km.
+const EARTH_RADIUS_KM: f64 = 6378.137;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Vec3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Vec3 {
+    fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    fn sub(self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+
+    fn dot(self, other: Vec3) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn norm(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+}
+
+/// Classical Keplerian orbital elements, used here as a lightweight stand-in
+/// for a full SGP4 propagator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrbitalElements {
+    pub semi_major_axis_km: f64,
+    pub eccentricity: f64,
+    pub inclination_deg: f64,
+    pub raan_deg: f64,
+    pub arg_perigee_deg: f64,
+    pub mean_anomaly_deg: f64,
+    pub epoch: DateTime<Utc>,
+}
+
+impl OrbitalElements {
+    fn mean_motion_rad_s(&self) -> f64 {
+        (EARTH_MU / self.semi_major_axis_km.powi(3)).sqrt()
+    }
+
+    pub fn apogee_radius_km(&self) -> f64 {
+        self.semi_major_axis_km * (1.0 + self.eccentricity)
+    }
+
+    pub fn perigee_radius_km(&self) -> f64 {
+        self.semi_major_axis_km * (1.0 - self.eccentricity)
+    }
+
+    /// Propagates the orbit to `at` and returns the position and velocity
+    /// vectors in a simple Earth-centered inertial frame (km, km/s).
+    fn state_at(&self, at: DateTime<Utc>) -> (Vec3, Vec3) {
+        let dt = (at - self.epoch).num_milliseconds() as f64 / 1000.0;
+        let n = self.mean_motion_rad_s();
+        let m0 = self.mean_anomaly_deg.to_radians();
+        let m = m0 + n * dt;
+
+        let e = self.eccentricity;
+        let mut ecc_anomaly = m;
+        for _ in 0..50 {
+            let f = ecc_anomaly - e * ecc_anomaly.sin() - m;
+            let f_prime = 1.0 - e * ecc_anomaly.cos();
+            let delta = f / f_prime;
+            ecc_anomaly -= delta;
+            if delta.abs() < 1e-12 {
+                break;
+            }
+        }
+
+        let a = self.semi_major_axis_km;
+        let cos_e = ecc_anomaly.cos();
+        let sin_e = ecc_anomaly.sin();
+        let r = a * (1.0 - e * cos_e);
+
+        // Perifocal position and velocity.
+        let p = a * (1.0 - e * e);
+        let x_pf = a * (cos_e - e);
+        let y_pf = a * (1.0 - e * e).sqrt() * sin_e;
+        let mu_over_p = (EARTH_MU / p).sqrt();
+        let vx_pf = -mu_over_p * (1.0 - e * e).sqrt() * sin_e * (a / r);
+        let vy_pf = mu_over_p * (1.0 - e * e) * cos_e * (a / r);
+
+        let raan = self.raan_deg.to_radians();
+        let incl = self.inclination_deg.to_radians();
+        let argp = self.arg_perigee_deg.to_radians();
+
+        let (cos_raan, sin_raan) = (raan.cos(), raan.sin());
+        let (cos_incl, sin_incl) = (incl.cos(), incl.sin());
+        let (cos_argp, sin_argp) = (argp.cos(), argp.sin());
+
+        // 3-1-3 (RAAN, inclination, argument of perigee) rotation from the
+        // perifocal frame into the ECI frame.
+        let r11 = cos_raan * cos_argp - sin_raan * sin_argp * cos_incl;
+        let r12 = -cos_raan * sin_argp - sin_raan * cos_argp * cos_incl;
+        let r21 = sin_raan * cos_argp + cos_raan * sin_argp * cos_incl;
+        let r22 = -sin_raan * sin_argp + cos_raan * cos_argp * cos_incl;
+        let r31 = sin_argp * sin_incl;
+        let r32 = cos_argp * sin_incl;
+
+        let position = Vec3::new(
+            r11 * x_pf + r12 * y_pf,
+            r21 * x_pf + r22 * y_pf,
+            r31 * x_pf + r32 * y_pf,
+        );
+        let velocity = Vec3::new(
+            r11 * vx_pf + r12 * vy_pf,
+            r21 * vx_pf + r22 * vy_pf,
+            r31 * vx_pf + r32 * vy_pf,
+        );
+
+        (position, velocity)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Satellite {
+    pub id: String,
+    pub name: String,
+    pub norad_id: u32,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude_km: f64,
+    pub velocity_km_s: f64,
+    pub elements: OrbitalElements,
+    pub last_updated: DateTime<Utc>,
+}
+
+impl Satellite {
+    /// Builds a satellite record from freshly parsed orbital elements,
+    /// computing its initial position/velocity at the element set's epoch.
+    pub fn from_elements(id: String, name: String, norad_id: u32, elements: OrbitalElements) -> Self {
+        let epoch = elements.epoch;
+        let mut satellite = Self {
+            id,
+            name,
+            norad_id,
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude_km: 0.0,
+            velocity_km_s: 0.0,
+            elements,
+            last_updated: epoch,
+        };
+        satellite.refresh_position(epoch);
+        satellite
+    }
+
+    fn refresh_position(&mut self, at: DateTime<Utc>) {
+        let (position, velocity) = self.elements.state_at(at);
+        let (lat, lon, alt) = eci_to_geodetic(position, at);
+
+        self.latitude = lat;
+        self.longitude = lon;
+        self.altitude_km = alt;
+        self.velocity_km_s = velocity.norm();
+        self.last_updated = at;
+    }
+}
+
+/// Rough Greenwich Mean Sidereal Time, in radians, used only to rotate our
+/// simplified ECI frame into an Earth-fixed frame for lat/lon reporting.
+fn gmst_radians(at: DateTime<Utc>) -> f64 {
+    let days_since_epoch = (at - DateTime::UNIX_EPOCH).num_milliseconds() as f64 / 86_400_000.0;
+    let gmst_deg = (280.460_618_37 + 360.985_647_366_29 * days_since_epoch).rem_euclid(360.0);
+    gmst_deg.to_radians()
+}
+
+fn eci_to_geodetic(position: Vec3, at: DateTime<Utc>) -> (f64, f64, f64) {
+    let theta = gmst_radians(at);
+    let (cos_t, sin_t) = (theta.cos(), theta.sin());
+
+    // Rotate ECI -> ECEF about the polar axis by -theta.
+    let x_ecef = cos_t * position.x + sin_t * position.y;
+    let y_ecef = -sin_t * position.x + cos_t * position.y;
+    let z_ecef = position.z;
+
+    let r = position.norm();
+    let latitude = (z_ecef / r).asin().to_degrees();
+    let longitude = y_ecef.atan2(x_ecef).to_degrees();
+    let altitude = r - EARTH_RADIUS_KM;
+
+    (latitude, longitude, altitude)
+}
+
+/// One screened conjunction between a pair of tracked objects.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConjunctionResult {
+    pub primary_id: String,
+    pub secondary_id: String,
+    pub time_of_closest_approach: DateTime<Utc>,
+    pub miss_distance_km: f64,
+    pub relative_velocity_km_s: f64,
+    pub probability_of_collision: Option<f64>,
+}
+
+/// Request body for `/api/satellites/conjunction-analysis`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConjunctionAnalysisRequest {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    #[serde(default = "default_step_seconds")]
+    pub step_seconds: f64,
+    pub miss_distance_threshold_km: f64,
+    #[serde(default)]
+    pub hard_body_radius_km: Option<f64>,
+    #[serde(default)]
+    pub combined_position_sigma_km: Option<f64>,
+}
+
+fn default_step_seconds() -> f64 {
+    30.0
+}
+
+/// Smallest sampling step we'll accept. A zero or negative step would leave
+/// `screen_pair`'s `t += step` loop unable to advance, spinning forever
+/// while holding the service lock.
+const MIN_STEP_SECONDS: f64 = 0.001;
+
+/// Largest sampling step we'll accept. Without this, a request with a
+/// normal `start`/`end` window but a huge `step_seconds` passes the
+/// `MAX_SAMPLES` ratio check (the computed sample count is tiny) yet
+/// produces a `chrono::Duration` so large that `t += step` in
+/// `screen_pair` overflows `DateTime` and panics, poisoning the service
+/// mutex for every other handler.
+const MAX_STEP_SECONDS: f64 = 365.0 * 24.0 * 3600.0;
+
+/// Largest number of `screen_pair` samples we'll take per satellite pair.
+/// `analyze_conjunctions` runs synchronously under the service lock, so an
+/// unbounded `(end - start) / step_seconds` would let one request block the
+/// worker thread (and every other connection it's serving) for as long as
+/// the sampling loop takes.
+const MAX_SAMPLES: f64 = 20_000.0;
+
+impl ConjunctionAnalysisRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.step_seconds < MIN_STEP_SECONDS {
+            return Err(format!(
+                "step_seconds must be >= {MIN_STEP_SECONDS}, got {}",
+                self.step_seconds
+            ));
+        }
+        if self.step_seconds > MAX_STEP_SECONDS {
+            return Err(format!(
+                "step_seconds must be <= {MAX_STEP_SECONDS}, got {}",
+                self.step_seconds
+            ));
+        }
+        if self.end < self.start {
+            return Err("end must not be before start".to_string());
+        }
+
+        let samples = (self.end - self.start).num_milliseconds() as f64
+            / (self.step_seconds * 1000.0);
+        if samples > MAX_SAMPLES {
+            return Err(format!(
+                "time range / step_seconds must be <= {MAX_SAMPLES} samples, got {samples:.0}"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+pub struct SatelliteService {
+    satellites: std::collections::HashMap<String, Satellite>,
+}
+
+impl SatelliteService {
+    pub fn new() -> Self {
+        let epoch = DateTime::parse_from_rfc3339("2026-07-28T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let defaults = vec![
+            Satellite {
+                id: "iss".to_string(),
+                name: "ISS (ZARYA)".to_string(),
+                norad_id: 25544,
+                latitude: 0.0,
+                longitude: 0.0,
+                altitude_km: 0.0,
+                velocity_km_s: 0.0,
+                elements: OrbitalElements {
+                    semi_major_axis_km: EARTH_RADIUS_KM + 420.0,
+                    eccentricity: 0.0006,
+                    inclination_deg: 51.64,
+                    raan_deg: 45.0,
+                    arg_perigee_deg: 90.0,
+                    mean_anomaly_deg: 0.0,
+                    epoch,
+                },
+                last_updated: epoch,
+            },
+            Satellite {
+                id: "hubble".to_string(),
+                name: "HST".to_string(),
+                norad_id: 20580,
+                latitude: 0.0,
+                longitude: 0.0,
+                altitude_km: 0.0,
+                velocity_km_s: 0.0,
+                elements: OrbitalElements {
+                    semi_major_axis_km: EARTH_RADIUS_KM + 540.0,
+                    eccentricity: 0.0003,
+                    inclination_deg: 28.47,
+                    raan_deg: 120.0,
+                    arg_perigee_deg: 45.0,
+                    mean_anomaly_deg: 180.0,
+                    epoch,
+                },
+                last_updated: epoch,
+            },
+            Satellite {
+                id: "starlink-1007".to_string(),
+                name: "STARLINK-1007".to_string(),
+                norad_id: 44713,
+                latitude: 0.0,
+                longitude: 0.0,
+                altitude_km: 0.0,
+                velocity_km_s: 0.0,
+                elements: OrbitalElements {
+                    semi_major_axis_km: EARTH_RADIUS_KM + 550.0,
+                    eccentricity: 0.0001,
+                    inclination_deg: 53.0,
+                    raan_deg: 200.0,
+                    arg_perigee_deg: 0.0,
+                    mean_anomaly_deg: 90.0,
+                    epoch,
+                },
+                last_updated: epoch,
+            },
+        ];
+
+        let mut satellites = std::collections::HashMap::new();
+        for mut sat in defaults {
+            sat.refresh_position(epoch);
+            satellites.insert(sat.id.clone(), sat);
+        }
+
+        Self { satellites }
+    }
+
+    pub fn get_all_satellites(&self) -> Vec<Satellite> {
+        self.satellites.values().cloned().collect()
+    }
+
+    pub fn get_satellite(&self, id: &str) -> Option<&Satellite> {
+        self.satellites.get(id)
+    }
+
+    /// Inserts or replaces a satellite in the in-memory catalog, returning
+    /// the previous record if this was an update rather than an addition.
+    pub fn upsert_satellite(&mut self, satellite: Satellite) -> Option<Satellite> {
+        self.satellites.insert(satellite.id.clone(), satellite)
+    }
+
+    pub fn update_satellite_positions(&mut self) {
+        let now = Utc::now();
+        for satellite in self.satellites.values_mut() {
+            satellite.refresh_position(now);
+        }
+    }
+
+    /// Pairwise conjunction screening over `[request.start, request.end]`.
+    ///
+    /// Stage 1 discards pairs whose orbits can never come within the miss
+    /// distance threshold using an apogee/perigee radius check. Stage 2
+    /// samples the surviving pairs' relative range at a fixed step and
+    /// brackets negative-to-positive sign changes of the range-rate (the dot
+    /// product of relative position and relative velocity) — a range
+    /// minimum, not a maximum — to locate candidate closest approaches,
+    /// which are then refined by bisection.
+    pub fn analyze_conjunctions(
+        &self,
+        request: &ConjunctionAnalysisRequest,
+    ) -> Vec<ConjunctionResult> {
+        let ids: Vec<&String> = self.satellites.keys().collect();
+        let mut results = Vec::new();
+
+        for i in 0..ids.len() {
+            for j in (i + 1)..ids.len() {
+                let a = &self.satellites[ids[i]];
+                let b = &self.satellites[ids[j]];
+
+                if !self.could_conjunct(a, b, request.miss_distance_threshold_km) {
+                    continue;
+                }
+
+                results.extend(self.screen_pair(a, b, request));
+            }
+        }
+
+        results.sort_by(|a, b| {
+            a.miss_distance_km
+                .partial_cmp(&b.miss_distance_km)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        results
+    }
+
+    /// Coarse apogee/perigee pre-filter: two orbits cannot come within
+    /// `threshold_km` of each other if one's perigee radius exceeds the
+    /// other's apogee radius by more than the threshold.
+    fn could_conjunct(&self, a: &Satellite, b: &Satellite, threshold_km: f64) -> bool {
+        let gap_ab = a.elements.perigee_radius_km() - b.elements.apogee_radius_km();
+        let gap_ba = b.elements.perigee_radius_km() - a.elements.apogee_radius_km();
+        gap_ab <= threshold_km && gap_ba <= threshold_km
+    }
+
+    fn screen_pair(
+        &self,
+        a: &Satellite,
+        b: &Satellite,
+        request: &ConjunctionAnalysisRequest,
+    ) -> Vec<ConjunctionResult> {
+        let step = chrono::Duration::milliseconds((request.step_seconds * 1000.0) as i64);
+        let mut t = request.start;
+
+        let range_rate = |time: DateTime<Utc>| -> (f64, Vec3, Vec3) {
+            let (pos_a, vel_a) = a.elements.state_at(time);
+            let (pos_b, vel_b) = b.elements.state_at(time);
+            let rel_pos = pos_a.sub(pos_b);
+            let rel_vel = vel_a.sub(vel_b);
+            (rel_pos.dot(rel_vel), rel_pos, rel_vel)
+        };
+
+        let mut conjunctions = Vec::new();
+        let (mut prev_rate, _, _) = range_rate(t);
+        let mut prev_t = t;
+
+        while t <= request.end {
+            t += step;
+            if t > request.end {
+                break;
+            }
+            let (rate, _, _) = range_rate(t);
+
+            // Only a negative-to-positive range-rate crossing is a closest
+            // approach (range was shrinking, now growing). A positive-to-
+            // negative crossing is a range *maximum* and must be skipped.
+            if prev_rate < 0.0 && rate >= 0.0 {
+                let tca = self.refine_tca(a, b, prev_t, t);
+                let (pos_a, vel_a) = a.elements.state_at(tca);
+                let (pos_b, vel_b) = b.elements.state_at(tca);
+                let rel_pos = pos_a.sub(pos_b);
+                let rel_vel = vel_a.sub(vel_b);
+                let miss_distance_km = rel_pos.norm();
+
+                if miss_distance_km <= request.miss_distance_threshold_km {
+                    let probability_of_collision = request.hard_body_radius_km.map(|radius| {
+                        probability_of_collision(
+                            rel_pos,
+                            rel_vel,
+                            radius,
+                            request.combined_position_sigma_km.unwrap_or(radius),
+                        )
+                    });
+
+                    conjunctions.push(ConjunctionResult {
+                        primary_id: a.id.clone(),
+                        secondary_id: b.id.clone(),
+                        time_of_closest_approach: tca,
+                        miss_distance_km,
+                        relative_velocity_km_s: rel_vel.norm(),
+                        probability_of_collision,
+                    });
+                }
+            }
+
+            prev_rate = rate;
+            prev_t = t;
+        }
+
+        conjunctions
+    }
+
+    /// Bisects the range-rate sign change between `low` and `high` down to
+    /// sub-second precision to locate the time of closest approach.
+    fn refine_tca(
+        &self,
+        a: &Satellite,
+        b: &Satellite,
+        low: DateTime<Utc>,
+        high: DateTime<Utc>,
+    ) -> DateTime<Utc> {
+        let rate_at = |time: DateTime<Utc>| -> f64 {
+            let (pos_a, vel_a) = a.elements.state_at(time);
+            let (pos_b, vel_b) = b.elements.state_at(time);
+            pos_a.sub(pos_b).dot(vel_a.sub(vel_b))
+        };
+
+        let mut low = low;
+        let mut high = high;
+        let low_sign = rate_at(low).signum();
+
+        for _ in 0..30 {
+            let mid = low + (high - low) / 2;
+            if (high - low).num_milliseconds() < 100 {
+                break;
+            }
+            if rate_at(mid).signum() == low_sign {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        low + (high - low) / 2
+    }
+}
+
+/// Approximates the probability of collision by projecting the combined
+/// position uncertainty onto the b-plane (perpendicular to relative
+/// velocity) and integrating an isotropic 2D Gaussian over a circular
+/// combined hard-body radius.
+fn probability_of_collision(
+    relative_position: Vec3,
+    relative_velocity: Vec3,
+    combined_radius_km: f64,
+    position_sigma_km: f64,
+) -> f64 {
+    let speed = relative_velocity.norm();
+    if speed < 1e-9 {
+        return 0.0;
+    }
+
+    // Component of the miss distance lying in the b-plane (the plane
+    // perpendicular to the relative velocity through the origin).
+    let along_track = relative_position.dot(relative_velocity) / speed;
+    let along_vec = Vec3::new(
+        relative_velocity.x * along_track / speed,
+        relative_velocity.y * along_track / speed,
+        relative_velocity.z * along_track / speed,
+    );
+    let b_plane_miss = relative_position.sub(along_vec).norm();
+
+    // A zero sigma collapses the position uncertainty to a point: the
+    // Gaussian exponentials below would divide by zero and produce NaN
+    // (which serde_json then silently serializes as `null`, indistinguishable
+    // from "Pc not requested"). Resolve it directly instead: Pc is 1 if the
+    // (certain) miss distance falls inside the hard-body radius, else 0.
+    if position_sigma_km <= 0.0 {
+        return if b_plane_miss <= combined_radius_km {
+            1.0
+        } else {
+            0.0
+        };
+    }
+
+    // Closed-form approximation for an isotropic 2D Gaussian (equal sigma in
+    // both b-plane axes) integrated over a circular hard-body footprint of
+    // radius `combined_radius_km`, centered `b_plane_miss` away from the
+    // Gaussian's mean: Pc = exp(-miss^2 / (2*sigma^2)) * (1 - exp(-R^2 /
+    // (2*sigma^2))). This grows with R and shrinks toward 0 as the miss
+    // distance grows, as it should.
+    let sigma2 = 2.0 * position_sigma_km * position_sigma_km;
+    let miss_term = (-(b_plane_miss * b_plane_miss) / sigma2).exp();
+    let radius_term = 1.0 - (-(combined_radius_km * combined_radius_km) / sigma2).exp();
+    (miss_term * radius_term).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circular_elements(inclination_deg: f64, epoch: DateTime<Utc>) -> OrbitalElements {
+        OrbitalElements {
+            semi_major_axis_km: EARTH_RADIUS_KM + 500.0,
+            eccentricity: 0.0,
+            inclination_deg,
+            raan_deg: 0.0,
+            arg_perigee_deg: 0.0,
+            mean_anomaly_deg: 0.0,
+            epoch,
+        }
+    }
+
+    /// Builds a head-on pair that, by construction, occupy the exact same
+    /// point in space at `epoch`: same circular radius, same RAAN/arg-perigee
+    /// and zero mean anomaly, but crossing orbital planes (equatorial vs.
+    /// polar). Their separation must shrink into `epoch` and grow back out of
+    /// it, giving a known time of closest approach with ~0 miss distance.
+    fn head_on_pair(epoch: DateTime<Utc>) -> (Satellite, Satellite) {
+        let a = Satellite::from_elements(
+            "a".to_string(),
+            "A".to_string(),
+            1,
+            circular_elements(0.0, epoch),
+        );
+        let b = Satellite::from_elements(
+            "b".to_string(),
+            "B".to_string(),
+            2,
+            circular_elements(90.0, epoch),
+        );
+        (a, b)
+    }
+
+    fn service_with(satellites: Vec<Satellite>) -> SatelliteService {
+        SatelliteService {
+            satellites: satellites.into_iter().map(|s| (s.id.clone(), s)).collect(),
+        }
+    }
+
+    #[test]
+    fn analyze_conjunctions_finds_known_head_on_tca() {
+        let epoch = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let (a, b) = head_on_pair(epoch);
+        let service = service_with(vec![a, b]);
+
+        let request = ConjunctionAnalysisRequest {
+            start: epoch - chrono::Duration::seconds(300),
+            end: epoch + chrono::Duration::seconds(300),
+            step_seconds: 30.0,
+            miss_distance_threshold_km: 50.0,
+            hard_body_radius_km: None,
+            combined_position_sigma_km: None,
+        };
+
+        let results = service.analyze_conjunctions(&request);
+        assert_eq!(results.len(), 1, "expected exactly one conjunction, got {results:?}");
+
+        let conjunction = &results[0];
+        assert!(conjunction.miss_distance_km < 1.0, "miss distance was {}", conjunction.miss_distance_km);
+        let offset_seconds = (conjunction.time_of_closest_approach - epoch).num_milliseconds() as f64 / 1000.0;
+        assert!(offset_seconds.abs() < 1.0, "TCA was {offset_seconds}s away from the known crossing");
+    }
+
+    #[test]
+    fn analyze_conjunctions_filters_out_pairs_beyond_threshold() {
+        let epoch = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let (a, b) = head_on_pair(epoch);
+        let service = service_with(vec![a, b]);
+
+        let request = ConjunctionAnalysisRequest {
+            start: epoch - chrono::Duration::seconds(300),
+            end: epoch + chrono::Duration::seconds(300),
+            step_seconds: 30.0,
+            // A negative threshold can never be met, regardless of the true
+            // (hard to pin down exactly) closest-approach distance for this
+            // geometry. With these equal orbit radii `could_conjunct` sees a
+            // 0 km gap, which exceeds -1 km, so the pair is dropped by the
+            // pre-filter before `screen_pair` runs; `miss_distance_km`,
+            // never negative, could not satisfy the threshold either.
+            miss_distance_threshold_km: -1.0,
+            hard_body_radius_km: None,
+            combined_position_sigma_km: None,
+        };
+
+        let results = service.analyze_conjunctions(&request);
+        assert_eq!(results.len(), 0, "threshold below zero must filter out every conjunction");
+    }
+
+    #[test]
+    fn could_conjunct_rejects_orbits_that_never_get_close() {
+        let epoch = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let low = Satellite::from_elements(
+            "low".to_string(),
+            "LOW".to_string(),
+            1,
+            OrbitalElements {
+                semi_major_axis_km: EARTH_RADIUS_KM + 400.0,
+                eccentricity: 0.0,
+                ..circular_elements(0.0, epoch)
+            },
+        );
+        let high = Satellite::from_elements(
+            "high".to_string(),
+            "HIGH".to_string(),
+            2,
+            OrbitalElements {
+                semi_major_axis_km: EARTH_RADIUS_KM + 2000.0,
+                eccentricity: 0.0,
+                ..circular_elements(0.0, epoch)
+            },
+        );
+        let service = service_with(vec![]);
+
+        assert!(!service.could_conjunct(&low, &high, 10.0));
+        assert!(service.could_conjunct(&low, &high, 2000.0));
+    }
+}
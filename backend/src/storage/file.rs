@@ -0,0 +1,113 @@
+use std::path::PathBuf;
+
+use tokio::sync::Mutex;
+
+use crate::satellite_service::Satellite;
+
+use super::{generate_reservation_id, CatalogStore, NewReservation, Reservation, ReservationStatus, ReservationStore, StorageError};
+
+/// `STORAGE_BACKEND=file`: a JSON document per collection, serialized
+/// behind a mutex so concurrent writers don't interleave writes.
+pub struct FileCatalogStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileCatalogStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            lock: Mutex::new(()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CatalogStore for FileCatalogStore {
+    async fn load_catalog(&self) -> Result<Vec<Satellite>, StorageError> {
+        let _guard = self.lock.lock().await;
+        read_json_or_default(&self.path).await
+    }
+
+    async fn save_catalog(&self, satellites: &[Satellite]) -> Result<(), StorageError> {
+        let _guard = self.lock.lock().await;
+        write_json(&self.path, satellites).await
+    }
+}
+
+pub struct FileReservationStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileReservationStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            lock: Mutex::new(()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReservationStore for FileReservationStore {
+    async fn create_reservation(&self, request: NewReservation) -> Result<Reservation, StorageError> {
+        let _guard = self.lock.lock().await;
+        let mut reservations: Vec<Reservation> = read_json_or_default(&self.path).await?;
+
+        if let Some(existing) = reservations.iter().find(|r| r.overlaps(&request)) {
+            return Err(StorageError::Conflict(format!(
+                "orbital shell '{}' is already reserved by '{}' for an overlapping window",
+                existing.orbital_shell, existing.owner
+            )));
+        }
+
+        let reservation = Reservation {
+            id: generate_reservation_id(),
+            owner: request.owner,
+            orbital_shell: request.orbital_shell,
+            window_start: request.window_start,
+            window_end: request.window_end,
+            status: ReservationStatus::Active,
+        };
+        reservations.push(reservation.clone());
+
+        write_json(&self.path, &reservations).await?;
+        Ok(reservation)
+    }
+
+    async fn list_reservations(&self) -> Result<Vec<Reservation>, StorageError> {
+        let _guard = self.lock.lock().await;
+        read_json_or_default(&self.path).await
+    }
+}
+
+async fn read_json_or_default<T>(path: &PathBuf) -> Result<Vec<T>, StorageError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    match tokio::fs::read(path).await {
+        Ok(bytes) => serde_json::from_slice(&bytes)
+            .map_err(|e| StorageError::Backend(format!("failed to parse {}: {e}", path.display()))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(StorageError::Backend(format!("failed to read {}: {e}", path.display()))),
+    }
+}
+
+async fn write_json<T>(path: &PathBuf, value: &T) -> Result<(), StorageError>
+where
+    T: serde::Serialize + ?Sized,
+{
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| StorageError::Backend(format!("failed to create {}: {e}", parent.display())))?;
+    }
+
+    let bytes = serde_json::to_vec_pretty(value)
+        .map_err(|e| StorageError::Backend(format!("failed to serialize {}: {e}", path.display())))?;
+
+    tokio::fs::write(path, bytes)
+        .await
+        .map_err(|e| StorageError::Backend(format!("failed to write {}: {e}", path.display())))
+}
@@ -0,0 +1,72 @@
+use tokio::sync::Mutex;
+
+use crate::satellite_service::Satellite;
+
+use super::{generate_reservation_id, CatalogStore, NewReservation, Reservation, ReservationStatus, ReservationStore, StorageError};
+
+/// Default `STORAGE_BACKEND=memory` store: state lives only for the life of
+/// the process, same as the original `Arc<Mutex<SatelliteService>>`-only
+/// design.
+#[derive(Default)]
+pub struct MemoryCatalogStore {
+    satellites: Mutex<Vec<Satellite>>,
+}
+
+impl MemoryCatalogStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl CatalogStore for MemoryCatalogStore {
+    async fn load_catalog(&self) -> Result<Vec<Satellite>, StorageError> {
+        Ok(self.satellites.lock().await.clone())
+    }
+
+    async fn save_catalog(&self, satellites: &[Satellite]) -> Result<(), StorageError> {
+        *self.satellites.lock().await = satellites.to_vec();
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct MemoryReservationStore {
+    reservations: Mutex<Vec<Reservation>>,
+}
+
+impl MemoryReservationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl ReservationStore for MemoryReservationStore {
+    async fn create_reservation(&self, request: NewReservation) -> Result<Reservation, StorageError> {
+        let mut reservations = self.reservations.lock().await;
+
+        if let Some(existing) = reservations.iter().find(|r| r.overlaps(&request)) {
+            return Err(StorageError::Conflict(format!(
+                "orbital shell '{}' is already reserved by '{}' for an overlapping window",
+                existing.orbital_shell, existing.owner
+            )));
+        }
+
+        let reservation = Reservation {
+            id: generate_reservation_id(),
+            owner: request.owner,
+            orbital_shell: request.orbital_shell,
+            window_start: request.window_start,
+            window_end: request.window_end,
+            status: ReservationStatus::Active,
+        };
+        reservations.push(reservation.clone());
+
+        Ok(reservation)
+    }
+
+    async fn list_reservations(&self) -> Result<Vec<Reservation>, StorageError> {
+        Ok(self.reservations.lock().await.clone())
+    }
+}
@@ -0,0 +1,158 @@
+pub mod file;
+pub mod memory;
+pub mod sqlite;
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::satellite_service::Satellite;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReservationStatus {
+    Active,
+    Cancelled,
+}
+
+/// A durable orbital-slot reservation: an owner claiming exclusive use of an
+/// orbital shell for a time window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reservation {
+    pub id: String,
+    pub owner: String,
+    pub orbital_shell: String,
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    pub status: ReservationStatus,
+}
+
+/// Caller-supplied fields for a new reservation; `id` and `status` are
+/// assigned by the store.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewReservation {
+    pub owner: String,
+    pub orbital_shell: String,
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+}
+
+impl Reservation {
+    fn overlaps(&self, other: &NewReservation) -> bool {
+        self.status == ReservationStatus::Active
+            && self.orbital_shell == other.orbital_shell
+            && self.window_start < other.window_end
+            && other.window_start < self.window_end
+    }
+}
+
+#[derive(Debug)]
+pub enum StorageError {
+    /// The requested reservation overlaps an existing one in the same
+    /// orbital shell.
+    Conflict(String),
+    /// The backend failed to read or write its underlying storage.
+    Backend(String),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::Conflict(msg) => write!(f, "reservation conflict: {msg}"),
+            StorageError::Backend(msg) => write!(f, "storage backend error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+/// Durable storage for the satellite catalog, so an imported catalog
+/// survives a restart.
+#[async_trait::async_trait]
+pub trait CatalogStore: Send + Sync {
+    async fn load_catalog(&self) -> Result<Vec<Satellite>, StorageError>;
+    async fn save_catalog(&self, satellites: &[Satellite]) -> Result<(), StorageError>;
+}
+
+/// Durable storage for reservations, responsible for id generation and
+/// rejecting overlapping reservations within the same orbital shell.
+#[async_trait::async_trait]
+pub trait ReservationStore: Send + Sync {
+    async fn create_reservation(&self, request: NewReservation) -> Result<Reservation, StorageError>;
+    async fn list_reservations(&self) -> Result<Vec<Reservation>, StorageError>;
+}
+
+pub(crate) fn generate_reservation_id() -> String {
+    // A millisecond timestamp plus a process-wide atomic counter is
+    // sufficient here: the counter alone keeps ids unique within one
+    // process, whichever store calls this, and the timestamp prefix makes
+    // a collision across restarts unlikely; that is acceptable for a
+    // reservation log rather than a security token.
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(1);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("res_{}_{n}", Utc::now().timestamp_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reservation(shell: &str, start: i64, end: i64, status: ReservationStatus) -> Reservation {
+        Reservation {
+            id: "res_existing".to_string(),
+            owner: "alice".to_string(),
+            orbital_shell: shell.to_string(),
+            window_start: DateTime::from_timestamp(start, 0).unwrap(),
+            window_end: DateTime::from_timestamp(end, 0).unwrap(),
+            status,
+        }
+    }
+
+    fn new_reservation(shell: &str, start: i64, end: i64) -> NewReservation {
+        NewReservation {
+            owner: "bob".to_string(),
+            orbital_shell: shell.to_string(),
+            window_start: DateTime::from_timestamp(start, 0).unwrap(),
+            window_end: DateTime::from_timestamp(end, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn overlaps_true_when_windows_intersect() {
+        let existing = reservation("leo-53", 100, 200, ReservationStatus::Active);
+        let candidate = new_reservation("leo-53", 150, 250);
+        assert!(existing.overlaps(&candidate));
+    }
+
+    #[test]
+    fn overlaps_false_for_adjacent_non_overlapping_windows() {
+        // Touching at a single instant (existing ends exactly when the
+        // candidate starts) must not count as a conflict.
+        let existing = reservation("leo-53", 100, 200, ReservationStatus::Active);
+        let candidate = new_reservation("leo-53", 200, 300);
+        assert!(!existing.overlaps(&candidate));
+    }
+
+    #[test]
+    fn overlaps_false_for_different_orbital_shell() {
+        let existing = reservation("leo-53", 100, 200, ReservationStatus::Active);
+        let candidate = new_reservation("leo-97", 150, 250);
+        assert!(!existing.overlaps(&candidate));
+    }
+
+    #[test]
+    fn overlaps_false_for_cancelled_reservation() {
+        let existing = reservation("leo-53", 100, 200, ReservationStatus::Cancelled);
+        let candidate = new_reservation("leo-53", 150, 250);
+        assert!(!existing.overlaps(&candidate));
+    }
+
+    #[test]
+    fn overlaps_true_when_candidate_window_is_fully_contained() {
+        let existing = reservation("leo-53", 100, 200, ReservationStatus::Active);
+        let candidate = new_reservation("leo-53", 120, 180);
+        assert!(existing.overlaps(&candidate));
+    }
+}
@@ -0,0 +1,217 @@
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use sqlx::Row;
+
+use crate::satellite_service::{OrbitalElements, Satellite};
+
+use super::{generate_reservation_id, CatalogStore, NewReservation, Reservation, ReservationStatus, ReservationStore, StorageError};
+
+/// `STORAGE_BACKEND=sqlite`: satellites and reservations in a single SQLite
+/// database, connected to via `sqlx`.
+pub struct SqliteStore {
+    pool: SqlitePool,
+}
+
+impl SqliteStore {
+    pub async fn connect(database_url: &str) -> Result<Self, StorageError> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(5)
+            .connect(database_url)
+            .await
+            .map_err(|e| StorageError::Backend(format!("failed to connect to {database_url}: {e}")))?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS satellites (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                norad_id INTEGER NOT NULL,
+                semi_major_axis_km REAL NOT NULL,
+                eccentricity REAL NOT NULL,
+                inclination_deg REAL NOT NULL,
+                raan_deg REAL NOT NULL,
+                arg_perigee_deg REAL NOT NULL,
+                mean_anomaly_deg REAL NOT NULL,
+                epoch TEXT NOT NULL
+            )",
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| StorageError::Backend(e.to_string()))?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS reservations (
+                id TEXT PRIMARY KEY,
+                owner TEXT NOT NULL,
+                orbital_shell TEXT NOT NULL,
+                window_start TEXT NOT NULL,
+                window_end TEXT NOT NULL,
+                status TEXT NOT NULL
+            )",
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| StorageError::Backend(e.to_string()))?;
+
+        Ok(Self { pool })
+    }
+}
+
+#[async_trait::async_trait]
+impl CatalogStore for SqliteStore {
+    async fn load_catalog(&self) -> Result<Vec<Satellite>, StorageError> {
+        let rows = sqlx::query(
+            "SELECT id, name, norad_id, semi_major_axis_km, eccentricity, inclination_deg,
+                    raan_deg, arg_perigee_deg, mean_anomaly_deg, epoch
+             FROM satellites",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| StorageError::Backend(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let epoch: String = row.try_get("epoch").map_err(|e| StorageError::Backend(e.to_string()))?;
+                let epoch: DateTime<Utc> = DateTime::parse_from_rfc3339(&epoch)
+                    .map_err(|e| StorageError::Backend(e.to_string()))?
+                    .with_timezone(&Utc);
+
+                let elements = OrbitalElements {
+                    semi_major_axis_km: row.try_get("semi_major_axis_km").map_err(|e| StorageError::Backend(e.to_string()))?,
+                    eccentricity: row.try_get("eccentricity").map_err(|e| StorageError::Backend(e.to_string()))?,
+                    inclination_deg: row.try_get("inclination_deg").map_err(|e| StorageError::Backend(e.to_string()))?,
+                    raan_deg: row.try_get("raan_deg").map_err(|e| StorageError::Backend(e.to_string()))?,
+                    arg_perigee_deg: row.try_get("arg_perigee_deg").map_err(|e| StorageError::Backend(e.to_string()))?,
+                    mean_anomaly_deg: row.try_get("mean_anomaly_deg").map_err(|e| StorageError::Backend(e.to_string()))?,
+                    epoch,
+                };
+
+                let id: String = row.try_get("id").map_err(|e| StorageError::Backend(e.to_string()))?;
+                let name: String = row.try_get("name").map_err(|e| StorageError::Backend(e.to_string()))?;
+                let norad_id: i64 = row.try_get("norad_id").map_err(|e| StorageError::Backend(e.to_string()))?;
+
+                Ok(Satellite::from_elements(id, name, norad_id as u32, elements))
+            })
+            .collect()
+    }
+
+    async fn save_catalog(&self, satellites: &[Satellite]) -> Result<(), StorageError> {
+        let mut tx = self.pool.begin().await.map_err(|e| StorageError::Backend(e.to_string()))?;
+
+        for satellite in satellites {
+            sqlx::query(
+                "INSERT INTO satellites (id, name, norad_id, semi_major_axis_km, eccentricity,
+                    inclination_deg, raan_deg, arg_perigee_deg, mean_anomaly_deg, epoch)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                 ON CONFLICT(id) DO UPDATE SET
+                    name = excluded.name,
+                    norad_id = excluded.norad_id,
+                    semi_major_axis_km = excluded.semi_major_axis_km,
+                    eccentricity = excluded.eccentricity,
+                    inclination_deg = excluded.inclination_deg,
+                    raan_deg = excluded.raan_deg,
+                    arg_perigee_deg = excluded.arg_perigee_deg,
+                    mean_anomaly_deg = excluded.mean_anomaly_deg,
+                    epoch = excluded.epoch",
+            )
+            .bind(&satellite.id)
+            .bind(&satellite.name)
+            .bind(satellite.norad_id as i64)
+            .bind(satellite.elements.semi_major_axis_km)
+            .bind(satellite.elements.eccentricity)
+            .bind(satellite.elements.inclination_deg)
+            .bind(satellite.elements.raan_deg)
+            .bind(satellite.elements.arg_perigee_deg)
+            .bind(satellite.elements.mean_anomaly_deg)
+            .bind(satellite.elements.epoch.to_rfc3339())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::Backend(e.to_string()))?;
+        }
+
+        tx.commit().await.map_err(|e| StorageError::Backend(e.to_string()))
+    }
+}
+
+#[async_trait::async_trait]
+impl ReservationStore for SqliteStore {
+    async fn create_reservation(&self, request: NewReservation) -> Result<Reservation, StorageError> {
+        let mut tx = self.pool.begin().await.map_err(|e| StorageError::Backend(e.to_string()))?;
+
+        let conflict = sqlx::query(
+            "SELECT owner FROM reservations
+             WHERE orbital_shell = ? AND status = 'active'
+               AND window_start < ? AND ? < window_end",
+        )
+        .bind(&request.orbital_shell)
+        .bind(request.window_end.to_rfc3339())
+        .bind(request.window_start.to_rfc3339())
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Backend(e.to_string()))?;
+
+        if let Some(row) = conflict {
+            let owner: String = row.try_get("owner").map_err(|e| StorageError::Backend(e.to_string()))?;
+            return Err(StorageError::Conflict(format!(
+                "orbital shell '{}' is already reserved by '{owner}' for an overlapping window",
+                request.orbital_shell
+            )));
+        }
+
+        let reservation = Reservation {
+            id: generate_reservation_id(),
+            owner: request.owner,
+            orbital_shell: request.orbital_shell,
+            window_start: request.window_start,
+            window_end: request.window_end,
+            status: ReservationStatus::Active,
+        };
+
+        sqlx::query(
+            "INSERT INTO reservations (id, owner, orbital_shell, window_start, window_end, status)
+             VALUES (?, ?, ?, ?, ?, 'active')",
+        )
+        .bind(&reservation.id)
+        .bind(&reservation.owner)
+        .bind(&reservation.orbital_shell)
+        .bind(reservation.window_start.to_rfc3339())
+        .bind(reservation.window_end.to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Backend(e.to_string()))?;
+
+        tx.commit().await.map_err(|e| StorageError::Backend(e.to_string()))?;
+        Ok(reservation)
+    }
+
+    async fn list_reservations(&self) -> Result<Vec<Reservation>, StorageError> {
+        let rows = sqlx::query("SELECT id, owner, orbital_shell, window_start, window_end, status FROM reservations")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| StorageError::Backend(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let window_start: String = row.try_get("window_start").map_err(|e| StorageError::Backend(e.to_string()))?;
+                let window_end: String = row.try_get("window_end").map_err(|e| StorageError::Backend(e.to_string()))?;
+                let status: String = row.try_get("status").map_err(|e| StorageError::Backend(e.to_string()))?;
+
+                Ok(Reservation {
+                    id: row.try_get("id").map_err(|e| StorageError::Backend(e.to_string()))?,
+                    owner: row.try_get("owner").map_err(|e| StorageError::Backend(e.to_string()))?,
+                    orbital_shell: row.try_get("orbital_shell").map_err(|e| StorageError::Backend(e.to_string()))?,
+                    window_start: DateTime::parse_from_rfc3339(&window_start)
+                        .map_err(|e| StorageError::Backend(e.to_string()))?
+                        .with_timezone(&Utc),
+                    window_end: DateTime::parse_from_rfc3339(&window_end)
+                        .map_err(|e| StorageError::Backend(e.to_string()))?
+                        .with_timezone(&Utc),
+                    status: if status == "active" {
+                        ReservationStatus::Active
+                    } else {
+                        ReservationStatus::Cancelled
+                    },
+                })
+            })
+            .collect()
+    }
+}